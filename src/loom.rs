@@ -0,0 +1,73 @@
+//! Thin shim over the atomics and interior-mutability primitives `Signal` is built on,
+//! so its state machine can run unmodified against either `core`/`std` (the normal
+//! build) or against [`loom`]'s model-checked equivalents under `cfg(loom)`. The loom
+//! build is exercised by the `loom_tests` module at the bottom of `signal.rs`
+//! (`RUSTFLAGS="--cfg loom" cargo test --release --lib -- loom_tests`), following the
+//! same pattern tokio uses for its `sync` primitives.
+//!
+//! `UnsafeCell` in particular can't just be re-exported: loom's version requires all
+//! access to go through `with`/`with_mut` so it can track the borrow for its model
+//! checker, whereas `core`'s exposes a raw `get`. This module wraps both behind the
+//! same `with`/`with_mut` API so `signal.rs` only has to be written once.
+
+#[cfg(not(loom))]
+pub(crate) mod sync {
+    pub(crate) use core::sync::atomic::{fence, AtomicU8, AtomicUsize, Ordering};
+    pub(crate) use std::sync::Mutex;
+
+    #[derive(Debug)]
+    pub(crate) struct UnsafeCell<T>(core::cell::UnsafeCell<T>);
+
+    impl<T> UnsafeCell<T> {
+        #[inline(always)]
+        pub(crate) fn new(data: T) -> Self {
+            Self(core::cell::UnsafeCell::new(data))
+        }
+
+        /// Safety: same as `core::cell::UnsafeCell::get` - the caller must not create
+        /// overlapping shared/mutable references through the pointer handed to `f`.
+        #[inline(always)]
+        pub(crate) unsafe fn with<R>(&self, f: impl FnOnce(*const T) -> R) -> R {
+            f(self.0.get())
+        }
+
+        /// Safety: same as `core::cell::UnsafeCell::get` - the caller must not create
+        /// overlapping shared/mutable references through the pointer handed to `f`.
+        #[inline(always)]
+        pub(crate) unsafe fn with_mut<R>(&self, f: impl FnOnce(*mut T) -> R) -> R {
+            f(self.0.get())
+        }
+    }
+}
+
+#[cfg(loom)]
+pub(crate) mod sync {
+    pub(crate) use loom::sync::atomic::{fence, AtomicU8, AtomicUsize, Ordering};
+    pub(crate) use loom::sync::Mutex;
+
+    #[derive(Debug)]
+    pub(crate) struct UnsafeCell<T>(loom::cell::UnsafeCell<T>);
+
+    impl<T> UnsafeCell<T> {
+        #[inline(always)]
+        pub(crate) fn new(data: T) -> Self {
+            Self(loom::cell::UnsafeCell::new(data))
+        }
+
+        /// Safety: same as `loom::cell::UnsafeCell::get_mut` used read-only - the
+        /// caller must not create overlapping shared/mutable references through the
+        /// pointer handed to `f`.
+        #[inline(always)]
+        pub(crate) unsafe fn with<R>(&self, f: impl FnOnce(*const T) -> R) -> R {
+            self.0.with(|ptr| f(ptr))
+        }
+
+        /// Safety: same as `loom::cell::UnsafeCell::get_mut` - the caller must not
+        /// create overlapping shared/mutable references through the pointer handed to
+        /// `f`.
+        #[inline(always)]
+        pub(crate) unsafe fn with_mut<R>(&self, f: impl FnOnce(*mut T) -> R) -> R {
+            self.0.with_mut(|ptr| f(ptr))
+        }
+    }
+}
@@ -1,19 +1,70 @@
-use crate::{backoff, pointer::KanalPtr};
-use core::{
-    cell::UnsafeCell,
-    sync::atomic::{fence, AtomicU8, Ordering},
+use crate::{
+    backoff,
+    loom::sync::{fence, AtomicU8, AtomicUsize, Mutex, Ordering, UnsafeCell},
+    pointer::KanalPtr,
 };
+use core::sync::atomic::AtomicBool;
 #[cfg(feature = "async")]
 use core::{
     task::{Poll, Waker},
     time::Duration,
 };
-use std::{thread::Thread, time::Instant};
+use std::{sync::Arc, thread::Thread, time::Instant};
 
 const UNLOCKED: u8 = 0;
 const TERMINATED: u8 = 1;
-const LOCKED: u8 = 2;
-const LOCKED_STARVATION: u8 = 3;
+const CANCELLED: u8 = 2;
+const LOCKED: u8 = 3;
+const LOCKED_STARVATION: u8 = 4;
+
+/// Outcome of waiting on a [`Signal`]: either the rendezvous completed (`Ready`), the
+/// peer dropped its side of the channel (`Terminated`), or the wait was cancelled
+/// through a [`CancellationToken`] without the channel itself being disconnected
+/// (`Cancelled`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SignalEvent {
+    Ready,
+    Terminated,
+    Cancelled,
+}
+
+impl SignalEvent {
+    #[inline(always)]
+    fn from_state(state: u8) -> Self {
+        match state {
+            UNLOCKED => SignalEvent::Ready,
+            TERMINATED => SignalEvent::Terminated,
+            CANCELLED => SignalEvent::Cancelled,
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// Outcome of [`Signal::poll_deadline`]: the same three outcomes as [`SignalEvent`],
+/// plus `TimedOut` for a deadline that passed before the signal was claimed.
+#[cfg(feature = "async")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TimedSignalEvent {
+    Ready,
+    Terminated,
+    Cancelled,
+    TimedOut,
+}
+
+#[cfg(feature = "async")]
+impl From<SignalEvent> for TimedSignalEvent {
+    fn from(event: SignalEvent) -> Self {
+        match event {
+            SignalEvent::Ready => TimedSignalEvent::Ready,
+            SignalEvent::Terminated => TimedSignalEvent::Terminated,
+            SignalEvent::Cancelled => TimedSignalEvent::Cancelled,
+        }
+    }
+}
+
+/// Sentinel stored in `Signal::channel_index` while the signal has not been claimed by
+/// any channel yet, or for signals that are only ever registered with a single channel.
+const NO_CHANNEL: usize = usize::MAX;
 
 /// `KanalWaker` is a structure to enable synchronization in both async and
 /// sync.
@@ -24,14 +75,34 @@ pub(crate) enum KanalWaker {
     Sync(UnsafeCell<Option<Thread>>),
     #[cfg(feature = "async")]
     Async(Waker),
+    /// Holds every sync thread currently parked on a broadcast signal, so `wake_all`
+    /// can unpark all of them on a single state transition instead of just one. A
+    /// real `Mutex`, not `UnsafeCell`: registration (`wait`) and draining (`wake_all`/
+    /// `cancel`) both push/take against this from arbitrary, genuinely concurrent
+    /// threads, unlike the single-waiter `Sync` variant above.
+    MultiSync(Mutex<Vec<Thread>>),
+    /// Holds every async waker currently registered on a broadcast signal, so
+    /// `wake_all` can wake all of them on a single state transition instead of just
+    /// one. See the `MultiSync` doc above for why this is a `Mutex`.
+    #[cfg(feature = "async")]
+    MultiAsync(Mutex<Vec<Waker>>),
 }
 
 /// `Signal<T>` struct is responsible for communicating between threads and
 /// coroutines for both reads and writes.
+///
+/// A signal can be registered in the wait-queues of more than one channel at the same
+/// time, which is how `select!` is built: whichever channel wins the `channel_index`
+/// CAS in [`Signal::try_claim_for_channel`] is the only one allowed to touch `ptr` or
+/// transition `state`, and the rest simply find the signal already claimed by someone
+/// else the next time they look at it and move on.
 pub struct Signal<T> {
     state: AtomicU8,
     ptr: KanalPtr<T>,
     waker: KanalWaker,
+    /// Index of the channel that claimed this signal, or [`NO_CHANNEL`] if it has not
+    /// been claimed yet (or is only ever registered with a single channel).
+    channel_index: AtomicUsize,
 }
 
 impl<T> Signal<T> {
@@ -43,21 +114,61 @@ impl<T> Signal<T> {
             state: AtomicU8::new(LOCKED),
             ptr: Default::default(),
             waker: KanalWaker::None,
+            channel_index: AtomicUsize::new(NO_CHANNEL),
         }
     }
 
     #[inline(always)]
     #[cfg(feature = "async")]
-    pub(crate) fn poll(&self) -> Poll<bool> {
+    pub(crate) fn poll(&self) -> Poll<SignalEvent> {
         let v = self.state.load(Ordering::Relaxed);
         if v < LOCKED {
             fence(Ordering::Acquire);
-            Poll::Ready(v == UNLOCKED)
+            Poll::Ready(SignalEvent::from_state(v))
         } else {
             Poll::Pending
         }
     }
 
+    /// Polls for the signal to complete by `until`, returning
+    /// `Poll::Ready(TimedOut)` once the deadline passes without the signal being
+    /// claimed, instead of `Poll::Pending` forever. This takes the signal out of
+    /// `LOCKED`/`LOCKED_STARVATION` via [`Signal::cancel`] before reporting the
+    /// timeout, so a `send`/`recv`/`terminate` that arrives afterwards is a no-op
+    /// instead of racing a reader that already gave up. This is what lets
+    /// `recv_timeout`/`send_timeout` work the same way in async as they already do
+    /// in sync via `wait_timeout`.
+    ///
+    /// `Signal` only arbitrates who wins the rendezvous; it doesn't know about the
+    /// channel's wait-queue, so it can't unlink itself from one. The future wrapping
+    /// this poll is responsible for removing its signal from the queue on early drop
+    /// (before a deadline or a successful poll), the same way it already must on a
+    /// successful `Poll::Ready`; [`Signal::cancel`] is the same primitive that
+    /// removal's `Drop` impl should call first, so a peer that's mid-`send`/`recv`
+    /// still loses cleanly to it instead of writing into an unlinked signal.
+    /// Safety: must only be called on a signal that has not already been read out via
+    /// `assume_init`/`recv`, the same requirement [`Signal::cancel`] has (`poll` itself
+    /// has no safety requirements; this one is `unsafe` because it calls `cancel`).
+    #[allow(unused)]
+    #[cfg(feature = "async")]
+    pub(crate) unsafe fn poll_deadline(&self, until: Instant) -> Poll<TimedSignalEvent> {
+        if let Poll::Ready(event) = self.poll() {
+            return Poll::Ready(event.into());
+        }
+        if Instant::now() < until {
+            return Poll::Pending;
+        }
+        if Self::cancel(self as *const Self) {
+            return Poll::Ready(TimedSignalEvent::TimedOut);
+        }
+        // Lost the race to `cancel`: a concurrent send/recv/terminate claimed the
+        // signal first, so its result wins over the timeout.
+        match self.poll() {
+            Poll::Ready(event) => Poll::Ready(event.into()),
+            Poll::Pending => unreachable!("cancel only fails when the signal already left LOCKED"),
+        }
+    }
+
     /// Signal to send data to a writer for specific kanal pointer
     #[inline(always)]
     #[cfg(feature = "async")]
@@ -66,6 +177,7 @@ impl<T> Signal<T> {
             state: AtomicU8::new(LOCKED),
             ptr,
             waker: KanalWaker::None,
+            channel_index: AtomicUsize::new(NO_CHANNEL),
         }
     }
 
@@ -75,17 +187,60 @@ impl<T> Signal<T> {
         Self {
             state: AtomicU8::new(LOCKED),
             ptr,
-            waker: KanalWaker::Sync(None.into()),
+            waker: KanalWaker::Sync(UnsafeCell::new(None)),
+            channel_index: AtomicUsize::new(NO_CHANNEL),
+        }
+    }
+
+    /// Returns a new broadcast signal that can wake an arbitrary number of parked sync
+    /// waiters in one state transition; see [`Signal::wake_all`].
+    #[inline(always)]
+    #[allow(unused)]
+    pub(crate) fn new_multi_sync() -> Self {
+        Self {
+            state: AtomicU8::new(LOCKED),
+            ptr: Default::default(),
+            waker: KanalWaker::MultiSync(Mutex::new(Vec::new())),
+            channel_index: AtomicUsize::new(NO_CHANNEL),
+        }
+    }
+
+    /// Returns a new broadcast signal that can wake an arbitrary number of parked
+    /// async waiters in one state transition; see [`Signal::wake_all`].
+    #[inline(always)]
+    #[allow(unused)]
+    #[cfg(feature = "async")]
+    pub(crate) fn new_multi_async() -> Self {
+        Self {
+            state: AtomicU8::new(LOCKED),
+            ptr: Default::default(),
+            waker: KanalWaker::MultiAsync(Mutex::new(Vec::new())),
+            channel_index: AtomicUsize::new(NO_CHANNEL),
+        }
+    }
+
+    /// Registers an additional async waker on a broadcast signal without replacing any
+    /// waker already registered, so more than one receiver can park on the same
+    /// signal. Safe to call from any number of receivers concurrently; the `Mutex`
+    /// backing `MultiAsync` serializes registration against `wake_all`/`cancel`
+    /// draining the same `Vec`.
+    /// Safety: must only be called on a signal created with `new_multi_async`.
+    #[allow(unused)]
+    #[cfg(feature = "async")]
+    pub(crate) unsafe fn register_multi_waker(&self, waker: &Waker) {
+        match &self.waker {
+            KanalWaker::MultiAsync(waiters) => waiters.lock().unwrap().push(waker.clone()),
+            _ => unreachable!(),
         }
     }
 
     /// Waits for finishing async signal for a short time
     #[cfg(feature = "async")]
-    pub(crate) fn async_blocking_wait(&self) -> bool {
+    pub(crate) fn async_blocking_wait(&self) -> SignalEvent {
         let v = self.state.load(Ordering::Relaxed);
         if v < LOCKED {
             fence(Ordering::Acquire);
-            return v == UNLOCKED;
+            return SignalEvent::from_state(v);
         }
 
         for _ in 0..32 {
@@ -93,7 +248,7 @@ impl<T> Signal<T> {
             let v = self.state.load(Ordering::Relaxed);
             if v < LOCKED {
                 fence(Ordering::Acquire);
-                return v == UNLOCKED;
+                return SignalEvent::from_state(v);
             }
         }
 
@@ -104,7 +259,7 @@ impl<T> Signal<T> {
             let v = self.state.load(Ordering::Relaxed);
             if v < LOCKED {
                 fence(Ordering::Acquire);
-                return v == UNLOCKED;
+                return SignalEvent::from_state(v);
             }
             // increase sleep_time gradually to 262 microseconds
             if sleep_time < (1 << 18) {
@@ -115,13 +270,13 @@ impl<T> Signal<T> {
 
     /// Waits for the signal event in sync mode,
     #[inline(always)]
-    pub(crate) fn wait(&self) -> bool {
+    pub(crate) fn wait(&self) -> SignalEvent {
         if let Some(res) = backoff::spin_option_yield_only(
             || {
                 let v = self.state.load(Ordering::Relaxed);
                 if v < LOCKED {
                     fence(Ordering::Acquire);
-                    return Some(v == UNLOCKED);
+                    return Some(SignalEvent::from_state(v));
                 }
                 None
             },
@@ -133,7 +288,7 @@ impl<T> Signal<T> {
             KanalWaker::Sync(waker) => {
                 // waker is not shared as the state is not `LOCKED_STARVATION`
                 unsafe {
-                    *waker.get() = Some(std::thread::current());
+                    waker.with_mut(|ptr| *ptr = Some(std::thread::current()));
                 }
                 match self.state.compare_exchange(
                     LOCKED,
@@ -146,23 +301,49 @@ impl<T> Signal<T> {
                         let v = self.state.load(Ordering::Relaxed);
                         if v < LOCKED {
                             fence(Ordering::Acquire);
-                            return v == UNLOCKED;
+                            return SignalEvent::from_state(v);
+                        }
+                    },
+                    Err(v) => SignalEvent::from_state(v),
+                }
+            }
+            KanalWaker::MultiSync(waiters) => {
+                // Several receivers can park on a broadcast signal at once, so unlike
+                // `Sync` above, losing the CAS below doesn't mean the result is ready:
+                // it can also mean an earlier waiter already moved the state to
+                // `LOCKED_STARVATION` on our behalf. The `Mutex` is what makes it sound
+                // for more than one of them to push its `Thread` here concurrently.
+                waiters.lock().unwrap().push(std::thread::current());
+                match self.state.compare_exchange(
+                    LOCKED,
+                    LOCKED_STARVATION,
+                    Ordering::Release,
+                    Ordering::Acquire,
+                ) {
+                    Ok(_) | Err(LOCKED_STARVATION) => loop {
+                        std::thread::park();
+                        let v = self.state.load(Ordering::Relaxed);
+                        if v < LOCKED {
+                            fence(Ordering::Acquire);
+                            return SignalEvent::from_state(v);
                         }
                     },
-                    Err(v) => v == UNLOCKED,
+                    Err(v) => SignalEvent::from_state(v),
                 }
             }
             #[cfg(feature = "async")]
-            KanalWaker::None | KanalWaker::Async(_) => unreachable!(),
+            KanalWaker::None | KanalWaker::Async(_) | KanalWaker::MultiAsync(_) => {
+                unreachable!()
+            }
         }
     }
 
     /// Waits for the signal event in sync mode with a timeout
-    pub(crate) fn wait_timeout(&self, until: Instant) -> bool {
+    pub(crate) fn wait_timeout(&self, until: Instant) -> SignalEvent {
         let v = self.state.load(Ordering::Relaxed);
         if v < LOCKED {
             fence(Ordering::Acquire);
-            return v == UNLOCKED;
+            return SignalEvent::from_state(v);
         }
         match self.state.compare_exchange(
             LOCKED,
@@ -174,15 +355,24 @@ impl<T> Signal<T> {
                 let v = self.state.load(Ordering::Relaxed);
                 if v < LOCKED {
                     fence(Ordering::Acquire);
-                    return v == UNLOCKED;
+                    return SignalEvent::from_state(v);
                 }
                 let now = Instant::now();
                 if now >= until {
-                    return self.state.load(Ordering::Acquire) == UNLOCKED;
+                    // A genuine timeout with nobody having woken us yet leaves the
+                    // signal parked at `LOCKED_STARVATION`, which isn't one of
+                    // `SignalEvent`'s terminal states; treat it like `Terminated`,
+                    // same as this method's caller already did when it only got a
+                    // bool. `poll_deadline` (async side) surfaces a real timeout.
+                    return if self.state.load(Ordering::Acquire) == UNLOCKED {
+                        SignalEvent::Ready
+                    } else {
+                        SignalEvent::Terminated
+                    };
                 }
                 std::thread::park_timeout(until - now);
             },
-            Err(v) => v == UNLOCKED,
+            Err(v) => SignalEvent::from_state(v),
         }
     }
 
@@ -206,7 +396,12 @@ impl<T> Signal<T> {
     pub(crate) fn will_wake(&self, waker: &Waker) -> bool {
         match &self.waker {
             KanalWaker::Async(w) => w.will_wake(waker),
-            KanalWaker::Sync(_) | KanalWaker::None => unreachable!(),
+            KanalWaker::Sync(_)
+            | KanalWaker::None
+            | KanalWaker::MultiSync(_)
+            | KanalWaker::MultiAsync(_) => {
+                unreachable!()
+            }
         }
     }
 
@@ -220,6 +415,43 @@ impl<T> Signal<T> {
         self.ptr.read()
     }
 
+    /// Returns true if the signal is still waiting to be claimed, i.e. its state is
+    /// `LOCKED` or `LOCKED_STARVATION`. A channel that finds a signal registered in more
+    /// than one wait-queue (`select!`) should check this before attempting to
+    /// `send`/`recv`/`terminate` it, and move on to its next waiter otherwise, since
+    /// another channel may have already claimed it.
+    #[allow(unused)]
+    #[inline(always)]
+    pub(crate) fn is_pending(&self) -> bool {
+        self.state.load(Ordering::Relaxed) >= LOCKED
+    }
+
+    /// Returns the index of the channel that claimed this signal, if it has been
+    /// claimed via [`Signal::send_for_channel`], [`Signal::recv_for_channel`] or
+    /// [`Signal::terminate_for_channel`]. Used by `select!` to report the winning
+    /// branch once the signal it registered across several channels is no longer
+    /// pending.
+    #[allow(unused)]
+    pub(crate) fn claimed_channel(&self) -> Option<usize> {
+        match self.channel_index.load(Ordering::Acquire) {
+            NO_CHANNEL => None,
+            index => Some(index),
+        }
+    }
+
+    /// Attempts to claim this signal for `channel`, succeeding for exactly one caller
+    /// even if the signal is registered in more than one channel's wait-queue at the
+    /// same time. Must be called, and must win, before touching `ptr` or transitioning
+    /// `state`: a losing caller has to leave both alone, since the winner may still be
+    /// reading or writing them. Used by `select!` via [`Signal::send_for_channel`],
+    /// [`Signal::recv_for_channel`] and [`Signal::terminate_for_channel`].
+    #[allow(unused)]
+    fn try_claim_for_channel(&self, channel: usize) -> bool {
+        self.channel_index
+            .compare_exchange(NO_CHANNEL, channel, Ordering::AcqRel, Ordering::Acquire)
+            .is_ok()
+    }
+
     /// Wakes the sleeping thread or coroutine
     unsafe fn wake(this: *const Self, state: u8) {
         match &(*this).waker {
@@ -229,10 +461,23 @@ impl<T> Signal<T> {
                     .compare_exchange(LOCKED, state, Ordering::Release, Ordering::Acquire)
                     .is_err()
                 {
-                    if let Some(thread) = (*waker.get()).as_ref() {
-                        let thread = thread.clone();
-                        (*this).state.store(state, Ordering::Release);
-                        thread.unpark();
+                    // The waiter already parked at `LOCKED_STARVATION`. CAS (rather
+                    // than unconditionally store) because a concurrent `cancel` may be
+                    // racing us for the same transition; only one of us should unpark
+                    // the waiter and win the final state.
+                    if (*this)
+                        .state
+                        .compare_exchange(
+                            LOCKED_STARVATION,
+                            state,
+                            Ordering::Release,
+                            Ordering::Acquire,
+                        )
+                        .is_ok()
+                    {
+                        if let Some(thread) = waker.with(|ptr| (*ptr).clone()) {
+                            thread.unpark();
+                        }
                     }
                 }
             }
@@ -242,11 +487,77 @@ impl<T> Signal<T> {
                 (*this).state.store(state, Ordering::Release);
                 w.wake();
             }
+            KanalWaker::MultiSync(_) => unreachable!(),
+            #[cfg(feature = "async")]
+            KanalWaker::None | KanalWaker::MultiAsync(_) => unreachable!(),
+        }
+    }
+
+    /// Wakes every thread or coroutine registered on a broadcast signal in a single
+    /// state transition, instead of just the one waiter that `wake` hands the race to.
+    /// Safety: must only be called on a signal created with `new_multi_sync`/
+    /// `new_multi_async`.
+    #[allow(unused)]
+    unsafe fn wake_all(this: *const Self, state: u8) {
+        match &(*this).waker {
+            KanalWaker::MultiSync(waiters) => {
+                let won = (*this)
+                    .state
+                    .compare_exchange(LOCKED, state, Ordering::Release, Ordering::Acquire)
+                    .is_ok()
+                    || (*this)
+                        .state
+                        .compare_exchange(
+                            LOCKED_STARVATION,
+                            state,
+                            Ordering::Release,
+                            Ordering::Acquire,
+                        )
+                        .is_ok();
+                if !won {
+                    // Lost to a concurrent `cancel()`; its result wins over ours.
+                    return;
+                }
+                for thread in std::mem::take(&mut *waiters.lock().unwrap()) {
+                    thread.unpark();
+                }
+            }
             #[cfg(feature = "async")]
-            KanalWaker::None => unreachable!(),
+            KanalWaker::MultiAsync(waiters) => {
+                if (*this)
+                    .state
+                    .compare_exchange(LOCKED, state, Ordering::Release, Ordering::Acquire)
+                    .is_err()
+                {
+                    // Lost to a concurrent `cancel()`; its result wins over ours.
+                    return;
+                }
+                for waker in std::mem::take(&mut *waiters.lock().unwrap()) {
+                    waker.wake();
+                }
+            }
+            _ => unreachable!(),
         }
     }
 
+    /// Terminates a broadcast signal and wakes every parked receiver with
+    /// `TERMINATED` in one transition; see [`Signal::wake_all`].
+    /// Safety: it's only safe to call once on a signal created with `new_multi_sync`/
+    /// `new_multi_async`.
+    #[allow(unused)]
+    pub(crate) unsafe fn terminate_all(this: *const Self) {
+        Self::wake_all(this, TERMINATED);
+    }
+
+    /// Wakes every receiver parked on a broadcast signal to read the value the
+    /// publisher just placed in the channel's shared slot; see [`Signal::wake_all`].
+    /// Safety: it's only safe to call on a signal created with `new_multi_sync`/
+    /// `new_multi_async` that is not terminated.
+    #[allow(unused)]
+    pub(crate) unsafe fn send_all(this: *const Self) {
+        Self::wake_all(this, UNLOCKED);
+    }
+
     /// Sends object to receive signal
     /// Safety: it's only safe to be called only once on the receive signals
     /// that are not terminated
@@ -280,6 +591,141 @@ impl<T> Signal<T> {
         Self::wake(this, TERMINATED);
     }
 
+    /// Sends object to a receive signal registered with more than one channel,
+    /// recording `channel` as the winning branch for `select!`. Returns `false`
+    /// without touching `ptr` if another channel claimed the signal first.
+    /// Safety: it's only safe to be called only once on the receive signals
+    /// that are not terminated, and only after observing `is_pending()`.
+    #[allow(unused)]
+    #[must_use]
+    pub(crate) unsafe fn send_for_channel(this: *const Self, d: T, channel: usize) -> bool {
+        if !(*this).try_claim_for_channel(channel) {
+            return false;
+        }
+        (*this).ptr.write(d);
+        Self::wake(this, UNLOCKED);
+        true
+    }
+
+    /// Receives object from a send signal registered with more than one channel,
+    /// recording `channel` as the winning branch for `select!`. Returns `None`
+    /// without touching `ptr` if another channel claimed the signal first.
+    /// Safety: it's only safe to be called only once on send signals that are
+    /// not terminated, and only after observing `is_pending()`.
+    #[allow(unused)]
+    #[must_use]
+    pub(crate) unsafe fn recv_for_channel(this: *const Self, channel: usize) -> Option<T> {
+        if !(*this).try_claim_for_channel(channel) {
+            return None;
+        }
+        let r = (*this).ptr.read();
+        Self::wake(this, UNLOCKED);
+        Some(r)
+    }
+
+    /// Terminates a signal registered with more than one channel, recording `channel`
+    /// as the branch that observed the termination first. Returns `false` if another
+    /// channel claimed the signal first.
+    /// Safety: it's only safe to be called only once on send/receive signals
+    /// that are not finished or terminated.
+    #[allow(unused)]
+    #[must_use]
+    pub(crate) unsafe fn terminate_for_channel(this: *const Self, channel: usize) -> bool {
+        if !(*this).try_claim_for_channel(channel) {
+            return false;
+        }
+        Self::wake(this, TERMINATED);
+        true
+    }
+
+    /// Cancels a parked signal without disconnecting the channel it belongs to,
+    /// transitioning it to `CANCELLED` and waking its waiter. Driven by
+    /// [`CancellationToken`]. Cancellation always loses cleanly to a concurrent
+    /// successful `send`/`recv`/`terminate`: if the signal has already left `LOCKED`
+    /// (data arrived, or the peer terminated it) this is a no-op and returns `false`.
+    /// Safety: must only be called on a signal that has not already been read out via
+    /// `assume_init`/`recv`.
+    pub(crate) unsafe fn cancel(this: *const Self) -> bool {
+        if (*this)
+            .state
+            .compare_exchange(LOCKED, CANCELLED, Ordering::Release, Ordering::Acquire)
+            .is_ok()
+        {
+            // Won outright before anyone parked; wake whatever waker is already
+            // registered.
+            match &(*this).waker {
+                // A sync waiter only parks after its own `LOCKED` -> `LOCKED_STARVATION`
+                // CAS, which now fails with `CANCELLED`, so it returns without parking.
+                // Its `Thread` slot is not read here: it is only shared once the state
+                // is `LOCKED_STARVATION`.
+                KanalWaker::Sync(_) => {}
+                KanalWaker::MultiSync(waiters) => {
+                    for thread in std::mem::take(&mut *waiters.lock().unwrap()) {
+                        thread.unpark();
+                    }
+                }
+                #[cfg(feature = "async")]
+                KanalWaker::Async(w) => w.clone().wake(),
+                #[cfg(feature = "async")]
+                KanalWaker::MultiAsync(waiters) => {
+                    for waker in std::mem::take(&mut *waiters.lock().unwrap()) {
+                        waker.wake();
+                    }
+                }
+                #[cfg(feature = "async")]
+                KanalWaker::None => {}
+            }
+            return true;
+        }
+        // Lost the first CAS: either a concurrent send/recv/terminate already claimed
+        // the signal and its data/termination wins, or a sync waiter parked first and
+        // moved the state to `LOCKED_STARVATION` itself (the only wakers that ever go
+        // through `LOCKED_STARVATION`; `Async`/`MultiAsync` never park so losing the
+        // first CAS always means the former for them). In the park case we race
+        // `wake`'s own CAS for the right to finish the transition.
+        match &(*this).waker {
+            KanalWaker::Sync(waker) => {
+                if (*this)
+                    .state
+                    .compare_exchange(
+                        LOCKED_STARVATION,
+                        CANCELLED,
+                        Ordering::Release,
+                        Ordering::Acquire,
+                    )
+                    .is_ok()
+                {
+                    if let Some(thread) = waker.with(|ptr| (*ptr).clone()) {
+                        thread.unpark();
+                    }
+                    true
+                } else {
+                    false
+                }
+            }
+            KanalWaker::MultiSync(waiters) => {
+                if (*this)
+                    .state
+                    .compare_exchange(
+                        LOCKED_STARVATION,
+                        CANCELLED,
+                        Ordering::Release,
+                        Ordering::Acquire,
+                    )
+                    .is_ok()
+                {
+                    for thread in std::mem::take(&mut *waiters.lock().unwrap()) {
+                        thread.unpark();
+                    }
+                    true
+                } else {
+                    false
+                }
+            }
+            _ => false,
+        }
+    }
+
     /// Loads pointer data and drops it in place
     /// Safety: it should only be used once, and only when data in ptr is valid
     /// and not moved.
@@ -316,6 +762,24 @@ impl<T> SignalTerminator<T> {
     pub(crate) unsafe fn terminate(&self) {
         Signal::terminate(self.0)
     }
+    /// Returns true if the signal behind this terminator is still waiting to be
+    /// claimed; see [`Signal::is_pending`].
+    #[allow(unused)]
+    pub(crate) fn is_pending(&self) -> bool {
+        unsafe { (*self.0).is_pending() }
+    }
+    #[allow(unused)]
+    pub(crate) unsafe fn send_for_channel(self, data: T, channel: usize) -> bool {
+        Signal::send_for_channel(self.0, data, channel)
+    }
+    #[allow(unused)]
+    pub(crate) unsafe fn recv_for_channel(self, channel: usize) -> Option<T> {
+        Signal::recv_for_channel(self.0, channel)
+    }
+    #[allow(unused)]
+    pub(crate) unsafe fn terminate_for_channel(&self, channel: usize) -> bool {
+        Signal::terminate_for_channel(self.0, channel)
+    }
 }
 
 impl<T> PartialEq<Signal<T>> for SignalTerminator<T> {
@@ -329,3 +793,316 @@ impl<T> PartialEq<Signal<T>> for SignalTerminator<T> {
 unsafe impl<T: Send> Send for SignalTerminator<T> {}
 // If internal<T> is safe to send Signal<T> is safe to send.
 unsafe impl<T: Send> Send for Signal<T> {}
+
+/// A lightweight hierarchical cancellation source, modeled after tokio's
+/// `CancellationToken`. Cancelling a token cancels every signal currently registered
+/// with it via [`CancellationToken::register`], and transitively cancels every child
+/// token created with [`CancellationToken::child_token`] along with everything
+/// registered with those. Cancelling a child has no effect on its parent or siblings.
+#[derive(Clone)]
+#[allow(unused)]
+pub(crate) struct CancellationToken {
+    inner: Arc<CancellationTokenInner>,
+}
+
+struct CancellationTokenInner {
+    cancelled: AtomicBool,
+    // Boxed because a single token can have signals of different `T` registered with
+    // it; each closure closes over the raw signal pointer and calls `Signal::cancel`.
+    // The `usize` alongside it is that same pointer, kept around so a `Registration`
+    // can find and remove its own entry without knowing `T`.
+    signals: Mutex<Vec<(usize, Box<dyn FnOnce() + Send>)>>,
+    children: Mutex<Vec<CancellationToken>>,
+}
+
+#[allow(unused)]
+impl CancellationToken {
+    pub(crate) fn new() -> Self {
+        Self {
+            inner: Arc::new(CancellationTokenInner {
+                cancelled: AtomicBool::new(false),
+                signals: Mutex::new(Vec::new()),
+                children: Mutex::new(Vec::new()),
+            }),
+        }
+    }
+
+    /// Returns true if this token (or one of its ancestors) has been cancelled.
+    pub(crate) fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::Acquire)
+    }
+
+    /// Creates a child token: cancelling `self` also cancels the child and everything
+    /// registered with it, but cancelling the child has no effect on `self`.
+    pub(crate) fn child_token(&self) -> Self {
+        let child = Self::new();
+        if self.is_cancelled() {
+            child.cancel();
+        } else {
+            self.inner.children.lock().unwrap().push(child.clone());
+        }
+        child
+    }
+
+    /// Registers a parked signal so that cancelling this token (or an ancestor of it)
+    /// cancels the signal via [`Signal::cancel`]. If the token is already cancelled,
+    /// the signal is cancelled immediately instead of being registered.
+    /// Safety: `signal` must outlive either the returned [`Registration`] being
+    /// dropped or this token (whichever comes first).
+    pub(crate) unsafe fn register<T>(&self, signal: *const Signal<T>) -> Registration {
+        let addr = signal as usize;
+        if self.is_cancelled() {
+            Signal::cancel(signal);
+        } else {
+            self.inner.signals.lock().unwrap().push((
+                addr,
+                Box::new(move || {
+                    unsafe { Signal::cancel(addr as *const Signal<T>) };
+                }),
+            ));
+        }
+        Registration {
+            token: self.clone(),
+            signal_addr: addr,
+        }
+    }
+
+    /// Trips the token: cancels every signal and child token registered with it. Idempotent.
+    pub(crate) fn cancel(&self) {
+        if self.inner.cancelled.swap(true, Ordering::AcqRel) {
+            return;
+        }
+        // Drain into locals and drop the locks before running any of this: a signal's
+        // `Registration` dropping as a side effect of `cancel_signal()` (or a child's
+        // own `cancel()`) locks these same mutexes, so holding them across the loop
+        // bodies would be at best pointless contention and at worst a deadlock.
+        let signals: Vec<_> = self.inner.signals.lock().unwrap().drain(..).collect();
+        let children: Vec<_> = self.inner.children.lock().unwrap().drain(..).collect();
+        for (_, cancel_signal) in signals {
+            cancel_signal();
+        }
+        for child in children {
+            child.cancel();
+        }
+    }
+}
+
+/// RAII handle for a [`CancellationToken::register`] call: dropping it removes the
+/// signal's entry from the token, so a signal that completes normally (without being
+/// cancelled) can be safely dropped or reused afterwards without leaving the token
+/// holding a dangling pointer to it.
+#[must_use]
+#[allow(unused)]
+pub(crate) struct Registration {
+    token: CancellationToken,
+    signal_addr: usize,
+}
+
+impl Drop for Registration {
+    fn drop(&mut self) {
+        self.token
+            .inner
+            .signals
+            .lock()
+            .unwrap()
+            .retain(|(addr, _)| *addr != self.signal_addr);
+    }
+}
+
+// Deterministic, single-threaded tests of the state-machine transitions that don't
+// need loom's model checking: each one drives `Signal` through a fixed sequence of
+// calls and asserts the resulting state, rather than exploring interleavings.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sync_signal() -> Signal<u32> {
+        Signal::new_sync(KanalPtr::default())
+    }
+
+    #[test]
+    fn cancel_before_anyone_waits_reports_cancelled() {
+        let signal = sync_signal();
+        assert!(unsafe { Signal::cancel(&signal as *const Signal<u32>) });
+        assert_eq!(signal.wait(), SignalEvent::Cancelled);
+    }
+
+    #[test]
+    fn cancel_loses_cleanly_to_a_completed_send() {
+        let signal = sync_signal();
+        unsafe { Signal::send(&signal as *const Signal<u32>, 7) };
+        assert!(!unsafe { Signal::cancel(&signal as *const Signal<u32>) });
+        assert_eq!(signal.wait(), SignalEvent::Ready);
+    }
+
+    /// Regression test for the channel-claim CAS: once one channel wins
+    /// `try_claim_for_channel`, a second channel racing for the same signal must not
+    /// be able to read `ptr` too (which would double-read a non-`Copy` `T`).
+    #[test]
+    fn recv_for_channel_only_the_winner_reads_ptr() {
+        let signal = sync_signal();
+        unsafe { Signal::send(&signal as *const Signal<u32>, 123) };
+
+        assert_eq!(
+            unsafe { Signal::recv_for_channel(&signal as *const Signal<u32>, 0) },
+            Some(123)
+        );
+        assert_eq!(
+            unsafe { Signal::recv_for_channel(&signal as *const Signal<u32>, 1) },
+            None
+        );
+        assert_eq!(signal.claimed_channel(), Some(0));
+    }
+
+    /// Checks `cancel`'s `LOCKED_STARVATION` fallback: a broadcast receiver
+    /// that already parked (pushed into `MultiSync`'s waiter list and moved the state
+    /// to `LOCKED_STARVATION`) must still be cancellable and woken, not silently
+    /// skipped by a `cancel()` that only checked the `LOCKED` fast path.
+    #[test]
+    fn cancel_wakes_a_parked_multi_sync_receiver() {
+        let signal = Signal::<u32>::new_multi_sync();
+        match &signal.waker {
+            KanalWaker::MultiSync(waiters) => waiters.lock().unwrap().push(std::thread::current()),
+            _ => unreachable!(),
+        }
+        assert!(signal
+            .state
+            .compare_exchange(
+                LOCKED,
+                LOCKED_STARVATION,
+                Ordering::Release,
+                Ordering::Acquire
+            )
+            .is_ok());
+
+        assert!(unsafe { Signal::cancel(&signal as *const Signal<u32>) });
+
+        assert_eq!(signal.state.load(Ordering::Acquire), CANCELLED);
+        match &signal.waker {
+            KanalWaker::MultiSync(waiters) => assert!(waiters.lock().unwrap().is_empty()),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn send_all_wakes_every_parked_multi_sync_receiver() {
+        let signal = Signal::<u32>::new_multi_sync();
+        match &signal.waker {
+            KanalWaker::MultiSync(waiters) => waiters.lock().unwrap().push(std::thread::current()),
+            _ => unreachable!(),
+        }
+
+        unsafe { Signal::send_all(&signal as *const Signal<u32>) };
+
+        assert_eq!(signal.state.load(Ordering::Acquire), UNLOCKED);
+        match &signal.waker {
+            KanalWaker::MultiSync(waiters) => assert!(waiters.lock().unwrap().is_empty()),
+            _ => unreachable!(),
+        }
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn poll_deadline_times_out_when_never_claimed() {
+        let signal = Signal::<u32>::new_async();
+        let already_past = Instant::now();
+        assert_eq!(
+            unsafe { signal.poll_deadline(already_past) },
+            Poll::Ready(TimedSignalEvent::TimedOut)
+        );
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn poll_deadline_reports_ready_if_already_claimed() {
+        struct NoopWake;
+        impl std::task::Wake for NoopWake {
+            fn wake(self: Arc<Self>) {}
+        }
+        let mut signal = Signal::<u32>::new_async();
+        // `wake` only handles a registered waker; a signal still at `KanalWaker::None`
+        // can't be sent to.
+        signal.register_waker(&Waker::from(Arc::new(NoopWake)));
+        unsafe { Signal::send(&signal as *const Signal<u32>, 5) };
+        let until = Instant::now() + Duration::from_secs(60);
+        assert_eq!(
+            unsafe { signal.poll_deadline(until) },
+            Poll::Ready(TimedSignalEvent::Ready)
+        );
+    }
+}
+
+// Model-checks the `wait`/`wake` handshake under `loom` instead of hand-waving the
+// `Relaxed` loads paired with the explicit `Acquire` fences. Run with:
+//   RUSTFLAGS="--cfg loom" cargo test --release --lib -- loom_tests
+// (release + a high LOOM_MAX_PREEMPTIONS is recommended; the state space here is large
+// enough that a debug build can take a while).
+#[cfg(all(test, loom))]
+mod loom_tests {
+    use super::*;
+
+    fn sync_signal() -> Signal<u32> {
+        Signal::new_sync(KanalPtr::default())
+    }
+
+    /// A send racing a consumer in `wait`: whether the consumer sees it while spinning
+    /// or gives up after the spin and parks at `LOCKED_STARVATION`, `wait` reports
+    /// `Ready`.
+    #[test]
+    fn send_vs_consumer_giving_up_after_spin() {
+        loom::model(|| {
+            let signal = loom::sync::Arc::new(sync_signal());
+            let s = signal.clone();
+            let sender = loom::thread::spawn(move || unsafe {
+                Signal::send(&*s as *const Signal<u32>, 1);
+            });
+
+            assert_eq!(signal.wait(), SignalEvent::Ready);
+            sender.join().unwrap();
+        });
+    }
+
+    /// `terminate` racing a `recv` on the same signal must resolve to exactly one
+    /// winner: either the value is read, or the signal observes `TERMINATED`, never
+    /// both and never neither.
+    #[test]
+    fn terminate_races_recv() {
+        loom::model(|| {
+            let signal = loom::sync::Arc::new(sync_signal());
+
+            let s = signal.clone();
+            let sender = loom::thread::spawn(move || unsafe {
+                Signal::send(&*s as *const Signal<u32>, 7);
+            });
+            let s = signal.clone();
+            let terminator = loom::thread::spawn(move || unsafe {
+                Signal::terminate(&*s as *const Signal<u32>);
+            });
+
+            let event = signal.wait();
+            assert!(event == SignalEvent::Ready || event == SignalEvent::Terminated);
+
+            sender.join().unwrap();
+            terminator.join().unwrap();
+        });
+    }
+
+    /// Same body as `send_vs_consumer_giving_up_after_spin`; the starvation-park path
+    /// is covered by the interleavings loom explores: the waiting thread stores its
+    /// `Thread` handle and CASes `LOCKED` -> `LOCKED_STARVATION` while a concurrent
+    /// `wake` may still observe the old `LOCKED` and win the outer CAS outright, or
+    /// observe `LOCKED_STARVATION` and have to fall back to reading the stored thread.
+    #[test]
+    fn starvation_park_path() {
+        loom::model(|| {
+            let signal = loom::sync::Arc::new(sync_signal());
+            let s = signal.clone();
+            let waker = loom::thread::spawn(move || unsafe {
+                Signal::send(&*s as *const Signal<u32>, 42);
+            });
+
+            assert_eq!(signal.wait(), SignalEvent::Ready);
+            waker.join().unwrap();
+        });
+    }
+}